@@ -0,0 +1,150 @@
+//! Condensation of a directed graph into its DAG of strongly connected
+//! components.
+//!
+//! Collapsing each cycle into a single super-node lets `topological_sort`
+//! produce a usable execution plan even when the original graph has cycles.
+
+use super::scc::strongly_connected_components;
+use crate::graph::DiGraph;
+use std::collections::BTreeSet;
+
+/// Collapse every strongly connected component of `graph` into a single
+/// super-node, returning the quotient graph plus a `node -> component_id`
+/// mapping.
+///
+/// Components are numbered in ascending order of their minimum original
+/// node index (the order `strongly_connected_components` returns them in),
+/// so ids are deterministic across runs. Each super-node's label preserves
+/// the original node labels as a comma-joined list, in ascending
+/// node-index order. Parallel edges between
+/// the same pair of components are deduplicated, and intra-component edges
+/// are dropped, so the result is always acyclic.
+pub fn condensation(graph: &DiGraph) -> (DiGraph, Vec<usize>) {
+    let components = strongly_connected_components(graph);
+
+    let n = graph.len();
+    let mut component_of = vec![0usize; n];
+    for (comp_id, component) in components.iter().enumerate() {
+        for &node in component {
+            component_of[node] = comp_id;
+        }
+    }
+
+    let mut quotient = DiGraph::new();
+    for component in &components {
+        let label = component
+            .iter()
+            .map(|&node| graph.label(node))
+            .collect::<Vec<_>>()
+            .join(", ");
+        quotient.add_node(&label);
+    }
+
+    let mut seen_edges: BTreeSet<(usize, usize)> = BTreeSet::new();
+    for u in 0..n {
+        for &v in graph.successors_slice(u) {
+            let cu = component_of[u];
+            let cv = component_of[v];
+            if cu != cv && seen_edges.insert((cu, cv)) {
+                quotient.add_edge(cu, cv);
+            }
+        }
+    }
+
+    (quotient, component_of)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::topo::topological_sort;
+
+    #[test]
+    fn test_three_cycle_collapses_to_single_node() {
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        g.add_edge(a, b);
+        g.add_edge(b, c);
+        g.add_edge(c, a);
+
+        let (quotient, component_of) = condensation(&g);
+        assert_eq!(quotient.len(), 1);
+        assert_eq!(component_of, vec![0, 0, 0]);
+        assert!(quotient.successors_slice(0).is_empty());
+    }
+
+    #[test]
+    fn test_bridge_between_two_cycles_is_acyclic() {
+        // cycle a<->b, then b->c, cycle c<->d
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        let d = g.add_node("d");
+        g.add_edge(a, b);
+        g.add_edge(b, a);
+        g.add_edge(b, c);
+        g.add_edge(c, d);
+        g.add_edge(d, c);
+
+        let (quotient, component_of) = condensation(&g);
+        assert_eq!(quotient.len(), 2);
+        assert_eq!(component_of[a], component_of[b]);
+        assert_eq!(component_of[c], component_of[d]);
+        assert_ne!(component_of[a], component_of[c]);
+
+        let from_ab = component_of[a];
+        let from_cd = component_of[c];
+        assert_eq!(quotient.successors_slice(from_ab), &[from_cd]);
+    }
+
+    #[test]
+    fn test_quotient_of_cyclic_graph_is_topologically_sortable() {
+        // Two cycles bridged together: a<->b, b->c, c<->d. The original
+        // graph has no topological order; its condensation does.
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        let d = g.add_node("d");
+        g.add_edge(a, b);
+        g.add_edge(b, a);
+        g.add_edge(b, c);
+        g.add_edge(c, d);
+        g.add_edge(d, c);
+
+        assert!(topological_sort(&g).is_none());
+
+        let (quotient, component_of) = condensation(&g);
+        let order = topological_sort(&quotient).expect("condensation must be acyclic");
+        assert_eq!(order.len(), quotient.len());
+
+        let from_ab = component_of[a];
+        let from_cd = component_of[c];
+        let pos_ab = order.iter().position(|&c| c == from_ab).unwrap();
+        let pos_cd = order.iter().position(|&c| c == from_cd).unwrap();
+        assert!(pos_ab < pos_cd);
+    }
+
+    #[test]
+    fn test_already_acyclic_graph_is_unchanged_shape() {
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        g.add_edge(a, b);
+
+        let (quotient, component_of) = condensation(&g);
+        assert_eq!(quotient.len(), 2);
+        assert_ne!(component_of[a], component_of[b]);
+    }
+
+    #[test]
+    fn test_empty_graph() {
+        let g = DiGraph::new();
+        let (quotient, component_of) = condensation(&g);
+        assert_eq!(quotient.len(), 0);
+        assert!(component_of.is_empty());
+    }
+}
@@ -0,0 +1,207 @@
+//! Greedy feedback-arc-set heuristic (Eades-Lin-Smyth).
+//!
+//! Suggests a small set of edges whose removal makes the graph acyclic, so
+//! the viewer can recommend which dependencies to cut to restore a valid
+//! execution plan.
+
+use crate::graph::DiGraph;
+
+/// Compute a (near-minimal) feedback arc set for `graph` using the
+/// Eades-Lin-Smyth linear-arrangement heuristic.
+///
+/// Builds a vertex ordering by repeatedly removing sinks (out-degree 0,
+/// appended to a right-list), sources (in-degree 0, appended to a
+/// left-list), and otherwise the vertex maximizing `out_degree - in_degree`
+/// (also appended to the left-list), using bucket lists keyed by that
+/// delta for near-linear selection. The final sequence is the left-list
+/// followed by the reversed right-list; any edge whose target appears
+/// earlier in that sequence than its source is reported as a feedback arc.
+pub fn greedy_feedback_arc_set(graph: &DiGraph) -> Vec<(usize, usize)> {
+    let n = graph.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let successors: Vec<Vec<usize>> = (0..n).map(|v| graph.successors_slice(v).to_vec()).collect();
+    let predecessors: Vec<Vec<usize>> = (0..n).map(|v| graph.predecessors_slice(v).to_vec()).collect();
+
+    let mut in_degree: Vec<i64> = predecessors.iter().map(|p| p.len() as i64).collect();
+    let mut out_degree: Vec<i64> = successors.iter().map(|s| s.len() as i64).collect();
+    let mut removed = vec![false; n];
+    let mut remaining = n;
+
+    // Bucket lists keyed by out_degree - in_degree, shifted into
+    // [0, 2n], for near-linear selection of the max-delta vertex once no
+    // sinks or sources remain. Entries go stale as degrees change;
+    // `pop_max_delta` skips them lazily.
+    let shift = n as i64;
+    let mut buckets: Vec<Vec<usize>> = vec![Vec::new(); 2 * n + 1];
+    for v in 0..n {
+        buckets[(out_degree[v] - in_degree[v] + shift) as usize].push(v);
+    }
+    let mut high = buckets.len() - 1;
+
+    let mut sinks: Vec<usize> = (0..n).filter(|&v| out_degree[v] == 0).collect();
+    let mut sources: Vec<usize> = (0..n).filter(|&v| in_degree[v] == 0).collect();
+
+    let mut left: Vec<usize> = Vec::with_capacity(n);
+    let mut right: Vec<usize> = Vec::with_capacity(n);
+
+    while remaining > 0 {
+        let picked = next_live(&mut sinks, &removed, |v| out_degree[v] == 0)
+            .map(|v| (v, true))
+            .or_else(|| next_live(&mut sources, &removed, |v| in_degree[v] == 0).map(|v| (v, false)));
+
+        let (v, is_sink) = match picked {
+            Some(pair) => pair,
+            None => {
+                let v = pop_max_delta(&mut buckets, &mut high, &removed, &in_degree, &out_degree, shift)
+                    .expect("remaining vertices but no candidate found");
+                (v, false)
+            }
+        };
+
+        if is_sink {
+            right.push(v);
+        } else {
+            left.push(v);
+        }
+
+        removed[v] = true;
+        remaining -= 1;
+
+        for &u in &predecessors[v] {
+            if removed[u] {
+                continue;
+            }
+            out_degree[u] -= 1;
+            let idx = (out_degree[u] - in_degree[u] + shift) as usize;
+            buckets[idx].push(u);
+            if idx > high {
+                high = idx;
+            }
+            if out_degree[u] == 0 {
+                sinks.push(u);
+            }
+        }
+        for &w in &successors[v] {
+            if removed[w] {
+                continue;
+            }
+            in_degree[w] -= 1;
+            let idx = (out_degree[w] - in_degree[w] + shift) as usize;
+            buckets[idx].push(w);
+            if idx > high {
+                high = idx;
+            }
+            if in_degree[w] == 0 {
+                sources.push(w);
+            }
+        }
+    }
+
+    right.reverse();
+    let order: Vec<usize> = left.into_iter().chain(right).collect();
+
+    let mut position = vec![0usize; n];
+    for (pos, &v) in order.iter().enumerate() {
+        position[v] = pos;
+    }
+
+    let mut feedback_arcs = Vec::new();
+    for (u, targets) in successors.iter().enumerate() {
+        for &v in targets {
+            if v == u || position[v] < position[u] {
+                feedback_arcs.push((u, v));
+            }
+        }
+    }
+    feedback_arcs
+}
+
+/// Pop vertices off `stack` until one that is both not yet removed and
+/// still satisfies `still_valid` (a sink/source check) is found.
+fn next_live(stack: &mut Vec<usize>, removed: &[bool], still_valid: impl Fn(usize) -> bool) -> Option<usize> {
+    while let Some(v) = stack.pop() {
+        if !removed[v] && still_valid(v) {
+            return Some(v);
+        }
+    }
+    None
+}
+
+/// Pop the live vertex with the current maximum `out_degree - in_degree`,
+/// scanning `buckets` down from `high` and discarding stale entries left
+/// behind by earlier degree updates.
+fn pop_max_delta(
+    buckets: &mut [Vec<usize>],
+    high: &mut usize,
+    removed: &[bool],
+    in_degree: &[i64],
+    out_degree: &[i64],
+    shift: i64,
+) -> Option<usize> {
+    loop {
+        while buckets[*high].is_empty() {
+            if *high == 0 {
+                return None;
+            }
+            *high -= 1;
+        }
+        let v = buckets[*high].pop().unwrap();
+        if removed[v] {
+            continue;
+        }
+        if (out_degree[v] - in_degree[v] + shift) as usize != *high {
+            continue;
+        }
+        return Some(v);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty() {
+        let g = DiGraph::new();
+        assert!(greedy_feedback_arc_set(&g).is_empty());
+    }
+
+    #[test]
+    fn test_dag_no_feedback() {
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        let d = g.add_node("d");
+        g.add_edge(a, b);
+        g.add_edge(a, c);
+        g.add_edge(b, d);
+        g.add_edge(c, d);
+        assert!(greedy_feedback_arc_set(&g).is_empty());
+    }
+
+    #[test]
+    fn test_three_cycle_breaks_one_edge() {
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        g.add_edge(a, b);
+        g.add_edge(b, c);
+        g.add_edge(c, a);
+        let fas = greedy_feedback_arc_set(&g);
+        assert_eq!(fas.len(), 1);
+    }
+
+    #[test]
+    fn test_self_loop_is_feedback() {
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        g.add_edge(a, a);
+        let fas = greedy_feedback_arc_set(&g);
+        assert_eq!(fas, vec![(a, a)]);
+    }
+}
@@ -2,9 +2,13 @@
 //!
 //! Higher-level algorithms built on core graph primitives.
 
+pub mod condensation;
+pub mod cycle_break;
+pub mod scc;
+pub mod transitive_reduction;
+
 // Advanced modules will be added as they're implemented:
 // pub mod topk_set;
 // pub mod coverage;
 // pub mod k_paths;
 // pub mod parallel_cut;
-// pub mod cycle_break;
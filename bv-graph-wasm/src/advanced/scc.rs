@@ -0,0 +1,171 @@
+//! Strongly-connected-component detection via Tarjan's algorithm.
+//!
+//! `topological_sort` only reports that a graph has a cycle; this module
+//! identifies exactly which nodes form each cyclic cluster.
+
+use crate::graph::DiGraph;
+
+/// Compute all strongly connected components of `graph` using Tarjan's
+/// single-pass algorithm with an explicit stack (no recursion, so it stays
+/// WASM-safe on large graphs).
+///
+/// Each component is sorted by node index, and components are ordered by
+/// their minimum node index, so the result is fully deterministic.
+pub fn strongly_connected_components(graph: &DiGraph) -> Vec<Vec<usize>> {
+    let n = graph.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let mut index = vec![usize::MAX; n];
+    let mut lowlink = vec![0usize; n];
+    let mut on_stack = vec![false; n];
+    let mut stack: Vec<usize> = Vec::new();
+    let mut counter = 0usize;
+    let mut components: Vec<Vec<usize>> = Vec::new();
+
+    // Explicit DFS call stack: (node, index of the next successor to visit).
+    let mut call_stack: Vec<(usize, usize)> = Vec::new();
+
+    for start in 0..n {
+        if index[start] != usize::MAX {
+            continue;
+        }
+        call_stack.push((start, 0));
+
+        while let Some(&(v, succ_idx)) = call_stack.last() {
+            if succ_idx == 0 {
+                index[v] = counter;
+                lowlink[v] = counter;
+                counter += 1;
+                stack.push(v);
+                on_stack[v] = true;
+            }
+
+            let successors = graph.successors_slice(v);
+            if succ_idx < successors.len() {
+                let w = successors[succ_idx];
+                call_stack.last_mut().unwrap().1 += 1;
+
+                if index[w] == usize::MAX {
+                    call_stack.push((w, 0));
+                } else if on_stack[w] {
+                    lowlink[v] = lowlink[v].min(index[w]);
+                }
+            } else {
+                call_stack.pop();
+                if let Some(&(caller, _)) = call_stack.last() {
+                    lowlink[caller] = lowlink[caller].min(lowlink[v]);
+                }
+
+                if lowlink[v] == index[v] {
+                    let mut component = Vec::new();
+                    loop {
+                        let w = stack.pop().unwrap();
+                        on_stack[w] = false;
+                        component.push(w);
+                        if w == v {
+                            break;
+                        }
+                    }
+                    component.sort_unstable();
+                    components.push(component);
+                }
+            }
+        }
+    }
+
+    components.sort_by_key(|c| c[0]);
+    components
+}
+
+/// Components that represent an actual cycle: components of size > 1, plus
+/// single-node components that are self-loops.
+///
+/// Lets the viewer highlight exactly the dependency clusters that block a
+/// valid topological order.
+pub fn cyclic_groups(graph: &DiGraph) -> Vec<Vec<usize>> {
+    strongly_connected_components(graph)
+        .into_iter()
+        .filter(|c| c.len() > 1 || (c.len() == 1 && has_self_loop(graph, c[0])))
+        .collect()
+}
+
+/// Whether `graph` contains any cycle (including self-loops).
+pub fn has_cycle(graph: &DiGraph) -> bool {
+    !cyclic_groups(graph).is_empty()
+}
+
+fn has_self_loop(graph: &DiGraph, node: usize) -> bool {
+    graph.successors_slice(node).contains(&node)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chain() -> DiGraph {
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        g.add_edge(a, b);
+        g.add_edge(b, c);
+        g
+    }
+
+    #[test]
+    fn test_chain_all_singletons() {
+        let g = chain();
+        let comps = strongly_connected_components(&g);
+        assert_eq!(comps, vec![vec![0], vec![1], vec![2]]);
+        assert!(!has_cycle(&g));
+    }
+
+    #[test]
+    fn test_three_cycle() {
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        g.add_edge(a, b);
+        g.add_edge(b, c);
+        g.add_edge(c, a);
+        let comps = strongly_connected_components(&g);
+        assert_eq!(comps, vec![vec![0, 1, 2]]);
+        assert!(has_cycle(&g));
+    }
+
+    #[test]
+    fn test_self_loop() {
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        g.add_edge(a, a);
+        assert!(has_cycle(&g));
+        assert_eq!(cyclic_groups(&g), vec![vec![0]]);
+    }
+
+    #[test]
+    fn test_empty_graph() {
+        let g = DiGraph::new();
+        assert!(strongly_connected_components(&g).is_empty());
+        assert!(!has_cycle(&g));
+    }
+
+    #[test]
+    fn test_two_components_with_bridge() {
+        // cycle a<->b, then b->c, cycle c<->d
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        let d = g.add_node("d");
+        g.add_edge(a, b);
+        g.add_edge(b, a);
+        g.add_edge(b, c);
+        g.add_edge(c, d);
+        g.add_edge(d, c);
+        let comps = strongly_connected_components(&g);
+        assert_eq!(comps, vec![vec![0, 1], vec![2, 3]]);
+    }
+}
@@ -0,0 +1,172 @@
+//! Transitive reduction of a DAG.
+//!
+//! Removes dependency edges that are implied by a longer path, so the
+//! viewer can render a de-cluttered graph and let users toggle "show
+//! implied dependencies" back on.
+
+use crate::algorithms::topo::topological_sort;
+use crate::graph::DiGraph;
+
+/// Result of a transitive-reduction attempt.
+pub struct ReductionResult {
+    /// The reduced graph, with redundant edges removed (DAG input only).
+    pub graph: DiGraph,
+    /// Edges that were dropped because an alternate path already implied them.
+    pub redundant_edges: Vec<(usize, usize)>,
+    /// False if `graph` was not a DAG, in which case `graph` is returned
+    /// unchanged and `redundant_edges` is empty.
+    pub is_dag: bool,
+}
+
+/// Compute the transitive reduction of `graph`.
+///
+/// Processes nodes in reverse topological order, maintaining a reachability
+/// bitset per node: for each node `u`, its direct successors are examined
+/// in topological order, and an edge `u -> v` is kept only if `v` is not
+/// already reachable through a previously kept successor of `u`; `v`'s
+/// reachability set is then unioned into `u`'s. Falls back to returning the
+/// input unchanged (with `is_dag = false`) if the graph has a cycle.
+pub fn transitive_reduction(graph: &DiGraph) -> ReductionResult {
+    let n = graph.len();
+
+    let order = match topological_sort(graph) {
+        Some(order) => order,
+        None => {
+            return ReductionResult {
+                graph: clone_graph(graph),
+                redundant_edges: Vec::new(),
+                is_dag: false,
+            };
+        }
+    };
+
+    let mut topo_rank = vec![0usize; n];
+    for (rank, &node) in order.iter().enumerate() {
+        topo_rank[node] = rank;
+    }
+
+    let mut reachable: Vec<Bitset> = (0..n).map(|_| Bitset::new(n)).collect();
+    let mut kept_edges: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let mut redundant_edges = Vec::new();
+
+    for &u in order.iter().rev() {
+        let mut succs = graph.successors_slice(u).to_vec();
+        succs.sort_by_key(|&v| topo_rank[v]);
+
+        for v in succs {
+            if reachable[u].get(v) {
+                redundant_edges.push((u, v));
+            } else {
+                kept_edges[u].push(v);
+                reachable[u].set(v);
+                let v_reach = reachable[v].clone();
+                reachable[u].union_with(&v_reach);
+            }
+        }
+    }
+
+    let mut reduced = DiGraph::new();
+    for idx in 0..n {
+        reduced.add_node(graph.label(idx));
+    }
+    for (u, kept) in kept_edges.iter().enumerate() {
+        for &v in kept {
+            reduced.add_edge(u, v);
+        }
+    }
+
+    ReductionResult {
+        graph: reduced,
+        redundant_edges,
+        is_dag: true,
+    }
+}
+
+fn clone_graph(graph: &DiGraph) -> DiGraph {
+    let n = graph.len();
+    let mut copy = DiGraph::new();
+    for idx in 0..n {
+        copy.add_node(graph.label(idx));
+    }
+    for u in 0..n {
+        for &v in graph.successors_slice(u) {
+            copy.add_edge(u, v);
+        }
+    }
+    copy
+}
+
+/// Minimal fixed-size bit set used for per-node reachability tracking.
+#[derive(Clone)]
+struct Bitset {
+    words: Vec<u64>,
+}
+
+impl Bitset {
+    fn new(n: usize) -> Self {
+        Bitset {
+            words: vec![0u64; n.div_ceil(64).max(1)],
+        }
+    }
+
+    fn get(&self, i: usize) -> bool {
+        (self.words[i / 64] >> (i % 64)) & 1 == 1
+    }
+
+    fn set(&mut self, i: usize) {
+        self.words[i / 64] |= 1 << (i % 64);
+    }
+
+    fn union_with(&mut self, other: &Bitset) {
+        for (a, b) in self.words.iter_mut().zip(other.words.iter()) {
+            *a |= b;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diamond_plus_shortcut() {
+        // a -> b -> d, a -> c -> d, and a redundant a -> d shortcut.
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        let d = g.add_node("d");
+        g.add_edge(a, b);
+        g.add_edge(a, c);
+        g.add_edge(b, d);
+        g.add_edge(c, d);
+        g.add_edge(a, d);
+
+        let result = transitive_reduction(&g);
+        assert!(result.is_dag);
+        assert_eq!(result.redundant_edges, vec![(a, d)]);
+        assert_eq!(result.graph.successors_slice(a).len(), 2);
+    }
+
+    #[test]
+    fn test_empty_graph() {
+        let g = DiGraph::new();
+        let result = transitive_reduction(&g);
+        assert!(result.is_dag);
+        assert!(result.redundant_edges.is_empty());
+        assert_eq!(result.graph.len(), 0);
+    }
+
+    #[test]
+    fn test_cyclic_falls_back() {
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        g.add_edge(a, b);
+        g.add_edge(b, a);
+
+        let result = transitive_reduction(&g);
+        assert!(!result.is_dag);
+        assert!(result.redundant_edges.is_empty());
+    }
+}
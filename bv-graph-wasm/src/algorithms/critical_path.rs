@@ -0,0 +1,164 @@
+//! Critical-path and longest-path analysis over weighted DAGs.
+//!
+//! Computes the longest-weighted path through the dependency graph in a
+//! single pass over the topological order, along with each node's slack, so
+//! the viewer can flag which issues are on the critical path versus which
+//! have scheduling room.
+
+use crate::algorithms::topo::topological_sort;
+use crate::graph::DiGraph;
+
+/// Result of a critical-path computation.
+pub struct CriticalPathResult {
+    /// Total length (sum of weights) of the critical path.
+    pub length: f64,
+    /// Node indices along one longest path, in order.
+    pub path: Vec<usize>,
+    /// Per-node slack: critical length minus the node's earliest-plus-latest
+    /// window. Zero slack means the node is on the critical path.
+    pub slack: Vec<f64>,
+}
+
+/// Compute the critical path of `graph`, using `weights[i]` as the
+/// effort/duration of node `i` (defaulting to `1.0` for any node not
+/// covered by `weights`).
+///
+/// Initializes `dist[n] = weight[n]` for every node, then relaxes edges in
+/// topological order as `dist[v] = max(dist[v], dist[u] + weight[v])`,
+/// tracking a predecessor for path reconstruction. Returns `None` if the
+/// graph is cyclic.
+pub fn critical_path(graph: &DiGraph, weights: &[f64]) -> Option<CriticalPathResult> {
+    let n = graph.len();
+    let order = topological_sort(graph)?;
+
+    if n == 0 {
+        return Some(CriticalPathResult {
+            length: 0.0,
+            path: Vec::new(),
+            slack: Vec::new(),
+        });
+    }
+
+    let weight = |i: usize| weights.get(i).copied().unwrap_or(1.0);
+
+    let mut earliest: Vec<f64> = (0..n).map(weight).collect();
+    let mut predecessor: Vec<Option<usize>> = vec![None; n];
+
+    for &u in &order {
+        let du = earliest[u];
+        for &v in graph.successors_slice(u) {
+            let candidate = du + weight(v);
+            if candidate > earliest[v] {
+                earliest[v] = candidate;
+                predecessor[v] = Some(u);
+            }
+        }
+    }
+
+    let mut latest: Vec<f64> = (0..n).map(weight).collect();
+    for &u in order.iter().rev() {
+        for &v in graph.successors_slice(u) {
+            let candidate = latest[v] + weight(u);
+            if candidate > latest[u] {
+                latest[u] = candidate;
+            }
+        }
+    }
+
+    let critical_length = earliest.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+
+    let end = earliest
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.partial_cmp(b.1).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(idx, _)| idx)?;
+
+    let mut path = vec![end];
+    let mut cursor = end;
+    while let Some(prev) = predecessor[cursor] {
+        path.push(prev);
+        cursor = prev;
+    }
+    path.reverse();
+
+    let slack: Vec<f64> = (0..n)
+        .map(|i| critical_length - (earliest[i] + latest[i] - weight(i)))
+        .collect();
+
+    Some(CriticalPathResult {
+        length: critical_length,
+        path,
+        slack,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_linear_chain_default_weights() {
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        g.add_edge(a, b);
+        g.add_edge(b, c);
+
+        let result = critical_path(&g, &[]).unwrap();
+        assert_eq!(result.length, 3.0);
+        assert_eq!(result.path, vec![a, b, c]);
+        assert_eq!(result.slack[a], 0.0);
+    }
+
+    #[test]
+    fn test_diamond_weighted() {
+        //     a(1)
+        //    /    \
+        //   b(5)  c(1)
+        //    \    /
+        //     d(1)
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        let d = g.add_node("d");
+        g.add_edge(a, b);
+        g.add_edge(a, c);
+        g.add_edge(b, d);
+        g.add_edge(c, d);
+
+        let weights = vec![1.0, 5.0, 1.0, 1.0];
+        let result = critical_path(&g, &weights).unwrap();
+        assert_eq!(result.length, 7.0);
+        assert_eq!(result.path, vec![a, b, d]);
+        assert!(result.slack[c] > 0.0);
+    }
+
+    #[test]
+    fn test_cyclic_returns_none() {
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        g.add_edge(a, b);
+        g.add_edge(b, a);
+        assert!(critical_path(&g, &[]).is_none());
+    }
+
+    #[test]
+    fn test_negative_weight_not_clamped_to_zero() {
+        let mut g = DiGraph::new();
+        g.add_node("a");
+        let result = critical_path(&g, &[-5.0]).unwrap();
+        assert_eq!(result.length, -5.0);
+    }
+
+    #[test]
+    fn test_empty_graph() {
+        let g = DiGraph::new();
+        let result = critical_path(&g, &[]).unwrap();
+        assert_eq!(result.length, 0.0);
+        assert!(result.path.is_empty());
+        assert!(result.slack.is_empty());
+    }
+}
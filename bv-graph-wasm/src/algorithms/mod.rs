@@ -0,0 +1,4 @@
+//! Core ordering algorithms (topological sort, critical path).
+
+pub mod critical_path;
+pub mod topo;
@@ -0,0 +1,239 @@
+//! Dominator tree construction (Cooper-Harvey-Kennedy).
+//!
+//! Node `d` dominates node `n` if every path from the root to `n` passes
+//! through `d`. For an issue graph this answers "which single upstream
+//! issue, if slipped, blocks this one no matter what" - a much stronger
+//! signal than the direct-blocker list in `reachability`.
+
+use crate::graph::DiGraph;
+
+/// Dominator tree rooted at a chosen start node, or at a synthetic root
+/// connected to every in-degree-0 node.
+pub struct DominatorTree {
+    /// Real node count (excludes the synthetic root, if one was used).
+    n: usize,
+    /// Immediate dominator of each real node; `None` for the root itself,
+    /// for nodes unreachable from the root, and (with a synthetic root) for
+    /// nodes whose immediate dominator is the synthetic root.
+    idom: Vec<Option<usize>>,
+}
+
+impl DominatorTree {
+    /// The immediate dominator of `node`, or `None` if it is the root, is
+    /// unreachable, or is immediately dominated by the synthetic root.
+    pub fn immediate_dominator(&self, node: usize) -> Option<usize> {
+        self.idom.get(node).copied().flatten()
+    }
+
+    /// The full dominator chain of `node`, from its immediate dominator up
+    /// to the root (not including `node` itself).
+    pub fn dominators(&self, node: usize) -> Vec<usize> {
+        let mut chain = Vec::new();
+        let mut current = self.immediate_dominator(node);
+        while let Some(d) = current {
+            chain.push(d);
+            current = self.immediate_dominator(d);
+        }
+        chain
+    }
+
+    /// The dominator tree as `(parent, child)` edges over real node
+    /// indices.
+    pub fn dominator_tree(&self) -> Vec<(usize, usize)> {
+        (0..self.n)
+            .filter_map(|node| self.idom[node].map(|parent| (parent, node)))
+            .collect()
+    }
+}
+
+/// Compute the dominator tree of `graph`.
+///
+/// If `root` is `None`, a synthetic root is connected to every
+/// in-degree-0 node so the whole graph (even with multiple sources) has a
+/// single well-defined root.
+///
+/// Implements the iterative Cooper-Harvey-Kennedy data-flow algorithm:
+/// process nodes in reverse postorder repeatedly, setting each node's
+/// immediate dominator to the common ancestor (found via the `intersect`
+/// walk up the dom-tree using postorder numbers) of all its already
+/// processed predecessors, iterating to a fixpoint.
+pub fn dominator_tree(graph: &DiGraph, root: Option<usize>) -> DominatorTree {
+    let n = graph.len();
+
+    // An out-of-range root falls back to the synthetic-root case rather
+    // than indexing out of bounds.
+    let root = root.filter(|&r| r < n);
+
+    // Synthetic root lives at index `n`, used only when no explicit root
+    // was given.
+    let (start, virtual_root) = match root {
+        Some(r) => (r, None),
+        None => (n, Some(n)),
+    };
+
+    let successors = |v: usize| -> Vec<usize> {
+        if Some(v) == virtual_root {
+            (0..n).filter(|&i| graph.in_degree(i) == 0).collect()
+        } else {
+            graph.successors_slice(v).to_vec()
+        }
+    };
+    let predecessors = |v: usize| -> Vec<usize> {
+        if Some(v) == virtual_root {
+            Vec::new()
+        } else {
+            let mut preds = graph.predecessors_slice(v).to_vec();
+            if virtual_root.is_some() && graph.in_degree(v) == 0 {
+                preds.push(n);
+            }
+            preds
+        }
+    };
+
+    // Reverse postorder via an explicit-stack DFS from `start`.
+    let total = n + if virtual_root.is_some() { 1 } else { 0 };
+    let mut visited = vec![false; total];
+    let mut postorder = Vec::with_capacity(total);
+    let mut stack: Vec<(usize, usize)> = vec![(start, 0)];
+    visited[start] = true;
+
+    while let Some(&(v, idx)) = stack.last() {
+        let succs = successors(v);
+        if idx < succs.len() {
+            stack.last_mut().unwrap().1 += 1;
+            let w = succs[idx];
+            if !visited[w] {
+                visited[w] = true;
+                stack.push((w, 0));
+            }
+        } else {
+            postorder.push(v);
+            stack.pop();
+        }
+    }
+
+    let mut postorder_index: Vec<Option<usize>> = vec![None; total];
+    for (i, &v) in postorder.iter().enumerate() {
+        postorder_index[v] = Some(i);
+    }
+    let rpo: Vec<usize> = postorder.iter().rev().copied().collect();
+
+    let mut idom: Vec<Option<usize>> = vec![None; total];
+    idom[start] = Some(start);
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for &v in &rpo {
+            if v == start {
+                continue;
+            }
+            let preds = predecessors(v);
+            let mut new_idom = None;
+            for p in preds {
+                if idom[p].is_none() {
+                    continue;
+                }
+                new_idom = Some(match new_idom {
+                    None => p,
+                    Some(current) => intersect(current, p, &idom, &postorder_index),
+                });
+            }
+            if new_idom.is_some() && new_idom != idom[v] {
+                idom[v] = new_idom;
+                changed = true;
+            }
+        }
+    }
+
+    // The root has no dominator of its own; translate the synthetic root
+    // away from the output entirely.
+    idom[start] = None;
+    let real_idom: Vec<Option<usize>> = (0..n).map(|i| idom[i].filter(|&d| d != n)).collect();
+
+    DominatorTree { n, idom: real_idom }
+}
+
+/// Walk up the dominator tree from `a` and `b` (using postorder numbers to
+/// know which side is further from the root) until they meet.
+fn intersect(mut a: usize, mut b: usize, idom: &[Option<usize>], postorder_index: &[Option<usize>]) -> usize {
+    while a != b {
+        while postorder_index[a] < postorder_index[b] {
+            a = idom[a].unwrap();
+        }
+        while postorder_index[b] < postorder_index[a] {
+            b = idom[b].unwrap();
+        }
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_linear_chain() {
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        g.add_edge(a, b);
+        g.add_edge(b, c);
+
+        let tree = dominator_tree(&g, Some(a));
+        assert_eq!(tree.immediate_dominator(a), None);
+        assert_eq!(tree.immediate_dominator(b), Some(a));
+        assert_eq!(tree.immediate_dominator(c), Some(b));
+        assert_eq!(tree.dominators(c), vec![b, a]);
+    }
+
+    #[test]
+    fn test_diamond_merge_point_dominated_only_by_root() {
+        //     a
+        //    / \
+        //   b   c
+        //    \ /
+        //     d
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        let d = g.add_node("d");
+        g.add_edge(a, b);
+        g.add_edge(a, c);
+        g.add_edge(b, d);
+        g.add_edge(c, d);
+
+        let tree = dominator_tree(&g, Some(a));
+        assert_eq!(tree.immediate_dominator(b), Some(a));
+        assert_eq!(tree.immediate_dominator(c), Some(a));
+        // d is reachable via both b and c, so only a dominates it.
+        assert_eq!(tree.immediate_dominator(d), Some(a));
+    }
+
+    #[test]
+    fn test_empty_graph() {
+        let g = DiGraph::new();
+        let tree = dominator_tree(&g, None);
+        assert!(tree.dominator_tree().is_empty());
+    }
+
+    #[test]
+    fn test_synthetic_root_for_multiple_sources() {
+        // Two independent chains: a -> b, c -> d.
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        let d = g.add_node("d");
+        g.add_edge(a, b);
+        g.add_edge(c, d);
+
+        let tree = dominator_tree(&g, None);
+        assert_eq!(tree.immediate_dominator(a), None);
+        assert_eq!(tree.immediate_dominator(c), None);
+        assert_eq!(tree.immediate_dominator(b), Some(a));
+        assert_eq!(tree.immediate_dominator(d), Some(c));
+    }
+}
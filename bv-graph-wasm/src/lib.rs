@@ -11,6 +11,8 @@ mod advanced;
 mod whatif;
 mod subgraph;
 mod reachability;
+mod paths;
+mod dominators;
 
 pub use graph::DiGraph;
 
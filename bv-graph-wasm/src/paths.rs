@@ -0,0 +1,203 @@
+//! Simple-path enumeration between two nodes.
+//!
+//! Lets the viewer show the concrete dependency chains connecting two
+//! issues, not just whether one is reachable from the other, and helps
+//! spot fan-out explosions that make an issue risky to reorder.
+
+use crate::graph::DiGraph;
+
+/// Enumerate every simple path (no repeated nodes) from `source` to
+/// `target`, optionally bounded by `max_len` edges to keep output
+/// tractable on dense graphs.
+///
+/// Uses DFS with an on-path visited set and backtracking.
+pub fn all_simple_paths(
+    graph: &DiGraph,
+    source: usize,
+    target: usize,
+    max_len: Option<usize>,
+) -> Vec<Vec<usize>> {
+    let n = graph.len();
+    if source >= n || target >= n {
+        return Vec::new();
+    }
+
+    let mut paths = Vec::new();
+    let mut visited = vec![false; n];
+
+    walk(graph, source, target, max_len, &mut visited, &mut paths);
+
+    paths
+}
+
+/// Count the simple paths from `source` to `target` without materializing
+/// each one.
+pub fn count_simple_paths(
+    graph: &DiGraph,
+    source: usize,
+    target: usize,
+    max_len: Option<usize>,
+) -> usize {
+    let n = graph.len();
+    if source >= n || target >= n {
+        return 0;
+    }
+
+    let mut visited = vec![false; n];
+    count_walk(graph, source, target, max_len, &mut visited)
+}
+
+/// Explicit-stack DFS with backtracking, recording every simple path found.
+///
+/// Each stack frame is `(node, next successor index to try)`; the path to
+/// the current top of the stack is the sequence of frame nodes, so no
+/// separate path buffer is needed. Kept iterative (like the other
+/// traversal modules, rather than recursive) so it stays WASM-safe on long
+/// dependency chains.
+fn walk(
+    graph: &DiGraph,
+    source: usize,
+    target: usize,
+    max_len: Option<usize>,
+    visited: &mut [bool],
+    paths: &mut Vec<Vec<usize>>,
+) {
+    let mut stack: Vec<(usize, usize)> = vec![(source, 0)];
+    visited[source] = true;
+
+    while let Some(&(node, succ_idx)) = stack.last() {
+        if node == target {
+            paths.push(stack.iter().map(|&(n, _)| n).collect());
+            visited[node] = false;
+            stack.pop();
+            continue;
+        }
+
+        if let Some(limit) = max_len {
+            if stack.len() > limit {
+                visited[node] = false;
+                stack.pop();
+                continue;
+            }
+        }
+
+        let successors = graph.successors_slice(node);
+        if succ_idx < successors.len() {
+            stack.last_mut().unwrap().1 += 1;
+            let next = successors[succ_idx];
+            if !visited[next] {
+                visited[next] = true;
+                stack.push((next, 0));
+            }
+        } else {
+            visited[node] = false;
+            stack.pop();
+        }
+    }
+}
+
+/// Same traversal as [`walk`], but only tallies a count instead of
+/// materializing each path.
+fn count_walk(
+    graph: &DiGraph,
+    source: usize,
+    target: usize,
+    max_len: Option<usize>,
+    visited: &mut [bool],
+) -> usize {
+    let mut total = 0;
+    let mut stack: Vec<(usize, usize)> = vec![(source, 0)];
+    visited[source] = true;
+
+    while let Some(&(node, succ_idx)) = stack.last() {
+        if node == target {
+            total += 1;
+            visited[node] = false;
+            stack.pop();
+            continue;
+        }
+
+        let depth = stack.len() - 1;
+        if let Some(limit) = max_len {
+            if depth >= limit {
+                visited[node] = false;
+                stack.pop();
+                continue;
+            }
+        }
+
+        let successors = graph.successors_slice(node);
+        if succ_idx < successors.len() {
+            stack.last_mut().unwrap().1 += 1;
+            let next = successors[succ_idx];
+            if !visited[next] {
+                visited[next] = true;
+                stack.push((next, 0));
+            }
+        } else {
+            visited[node] = false;
+            stack.pop();
+        }
+    }
+
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diamond_two_paths() {
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        let d = g.add_node("d");
+        g.add_edge(a, b);
+        g.add_edge(a, c);
+        g.add_edge(b, d);
+        g.add_edge(c, d);
+
+        let paths = all_simple_paths(&g, a, d, None);
+        assert_eq!(paths.len(), 2);
+        assert_eq!(count_simple_paths(&g, a, d, None), 2);
+    }
+
+    #[test]
+    fn test_max_len_bound() {
+        // a -> b -> c -> d, plus a direct a -> d shortcut.
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        let d = g.add_node("d");
+        g.add_edge(a, b);
+        g.add_edge(b, c);
+        g.add_edge(c, d);
+        g.add_edge(a, d);
+
+        let all = all_simple_paths(&g, a, d, None);
+        assert_eq!(all.len(), 2);
+
+        let bounded = all_simple_paths(&g, a, d, Some(1));
+        assert_eq!(bounded, vec![vec![a, d]]);
+        assert_eq!(count_simple_paths(&g, a, d, Some(1)), 1);
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        assert!(all_simple_paths(&g, a, b, None).is_empty());
+        assert_eq!(count_simple_paths(&g, a, b, None), 0);
+    }
+
+    #[test]
+    fn test_out_of_range_nodes() {
+        let mut g = DiGraph::new();
+        g.add_node("a");
+        assert!(all_simple_paths(&g, 0, 99, None).is_empty());
+    }
+}